@@ -177,6 +177,122 @@ impl PlayerPhysics {
             0.0 // Return 0 if not moving (rotation won't change)
         }
     }
+
+    /// Solve `0 = pos.y - ground_y + v_y*t + 0.5*gravity*t^2` for the smallest
+    /// non-negative `t`, returning `None` if already grounded or no real landing
+    /// time exists (e.g. upward escape velocity with no ceiling).
+    fn solve_landing_time(&self, ground_y: f32) -> Option<f32> {
+        if self.is_grounded {
+            return None;
+        }
+
+        let a = 0.5 * self.gravity;
+        let b = self.velocity.y;
+        let c = self.position.y - ground_y;
+
+        if a == 0.0 {
+            let t = -c / b;
+            return if b != 0.0 && t >= 0.0 { Some(t) } else { None };
+        }
+
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let t1 = (-b + sqrt_d) / (2.0 * a);
+        let t2 = (-b - sqrt_d) / (2.0 * a);
+
+        match (t1 >= 0.0, t2 >= 0.0) {
+            (true, true) => Some(t1.min(t2)),
+            (true, false) => Some(t1),
+            (false, true) => Some(t2),
+            (false, false) => None,
+        }
+    }
+
+    /// Predict where an airborne player will touch down, by integrating the current
+    /// velocity forward in closed form instead of stepping frame by frame. Returns
+    /// the current position when already grounded or when no real solution exists.
+    pub fn predict_landing(&self, ground_y: f32) -> Vector3 {
+        match self.solve_landing_time(ground_y) {
+            Some(t) => Vector3::new(
+                self.position.x + self.velocity.x * t,
+                ground_y,
+                self.position.z + self.velocity.z * t,
+            ),
+            None => Vector3::new(self.position.x, self.position.y, self.position.z),
+        }
+    }
+
+    /// Number of steps (frames) until `predict_landing` would be reached; 0.0 if
+    /// already grounded or no real landing time exists.
+    pub fn time_to_land(&self, ground_y: f32) -> f32 {
+        self.solve_landing_time(ground_y).unwrap_or(0.0)
+    }
+}
+
+// Influence radius added on top of each obstacle's own radius, beyond which it no
+// longer contributes a repulsion vector.
+const STEERING_INFLUENCE_RADIUS: f32 = 1.0;
+
+/// Lightweight local steering layer that reacts to moving obstacles between static
+/// A* path recomputes: a "seek" vector toward the current waypoint, plus repulsion
+/// vectors from nearby obstacles, clamped to the agent's move speed.
+#[wasm_bindgen]
+pub struct Steering {
+    move_speed: f32,
+}
+
+#[wasm_bindgen]
+impl Steering {
+    #[wasm_bindgen(constructor)]
+    pub fn new(move_speed: f32) -> Steering {
+        Steering { move_speed }
+    }
+
+    /// Compute a desired [vx, vz] velocity that seeks (target_x, target_z) from
+    /// (px, pz) while steering around obstacles. `obstacles` is a flat
+    /// [x1, z1, r1, x2, z2, r2, ...] array of obstacle positions and radii.
+    pub fn avoid(&self, px: f32, pz: f32, target_x: f32, target_z: f32, obstacles: &[f32]) -> Vec<f32> {
+        let seek_x = target_x - px;
+        let seek_z = target_z - pz;
+        let seek_len = (seek_x * seek_x + seek_z * seek_z).sqrt();
+
+        let (mut vx, mut vz) = if seek_len > 0.0 {
+            (seek_x / seek_len * self.move_speed, seek_z / seek_len * self.move_speed)
+        } else {
+            (0.0, 0.0)
+        };
+
+        for obstacle in obstacles.chunks(3) {
+            if obstacle.len() < 3 {
+                continue;
+            }
+            let (ox, oz, oradius) = (obstacle[0], obstacle[1], obstacle[2]);
+
+            let dx = px - ox;
+            let dz = pz - oz;
+            let dist = (dx * dx + dz * dz).sqrt();
+            let influence = oradius + STEERING_INFLUENCE_RADIUS;
+
+            if dist > 0.0 && dist < influence {
+                // Inversely proportional to distance, zeroed out at the influence radius
+                let strength = 1.0 / dist - 1.0 / influence;
+                vx += (dx / dist) * strength * self.move_speed;
+                vz += (dz / dist) * strength * self.move_speed;
+            }
+        }
+
+        let len = (vx * vx + vz * vz).sqrt();
+        if len > self.move_speed {
+            vx = vx / len * self.move_speed;
+            vz = vz / len * self.move_speed;
+        }
+
+        vec![vx, vz]
+    }
 }
 
 use std::collections::{BinaryHeap, HashMap};
@@ -212,12 +328,43 @@ impl Ord for PathNode {
     }
 }
 
+// Base movement costs, borrowed from the "action cost" approach baritone-style
+// agents use: a plain walk vs. a sprint are priced differently, and diagonal
+// steps are priced by their actual grid distance.
+const WALK_COST: f32 = 1.0;
+const SPRINT_COST: f32 = WALK_COST * 0.78;
+const DIAGONAL_MULTIPLIER: f32 = 1.414;
+
+// Precomputed cost of dropping `n` cells, indexed by fall distance, so a
+// vertical transition can be priced without a sqrt/loop per expansion.
+const MAX_FALL_LOOKUP: usize = 32;
+
+fn build_fall_cost_table() -> Vec<f32> {
+    (0..MAX_FALL_LOOKUP)
+        .map(|n| WALK_COST + (n as f32 * 0.5).sqrt())
+        .collect()
+}
+
+// A height difference at or below this (in world units) is treated as flat
+// ground for the purposes of a normal walking step; anything taller needs a
+// jump or drop edge instead.
+const STEP_HEIGHT: f32 = 0.5;
+
+// Extra cost on top of the base move cost for jump/drop edges, and how far
+// (in cells) a jump arc is allowed to cover.
+const JUMP_COST: f32 = 2.5;
+const DROP_COST: f32 = 1.2;
+const MAX_JUMP_CELLS: i32 = 4;
+
 #[wasm_bindgen]
 pub struct Pathfinder {
     grid: Vec<bool>, // true = walkable, false = blocked
+    cost: Vec<f32>,  // per-cell movement cost multiplier; f32::INFINITY = blocked
+    heights: Vec<f32>, // terrain height per cell, used by jump/drop edges
     grid_size: usize,
     cell_size: f32,
     world_offset: f32, // Offset to center grid (world_size / 2)
+    fall_cost: Vec<f32>,
 }
 
 #[wasm_bindgen]
@@ -227,9 +374,12 @@ impl Pathfinder {
         let total_cells = grid_size * grid_size;
         Pathfinder {
             grid: vec![true; total_cells], // Initialize all as walkable
+            cost: vec![WALK_COST; total_cells],
+            heights: vec![0.0; total_cells],
             grid_size,
             cell_size,
             world_offset: world_size / 2.0,
+            fall_cost: build_fall_cost_table(),
         }
     }
 
@@ -290,6 +440,25 @@ impl Pathfinder {
         }
     }
 
+    /// Set the movement cost multiplier for a cell (e.g. roads < 1.0, mud/water > 1.0)
+    /// Use `f32::INFINITY` to mark a cell as effectively blocked for weighted pathing.
+    pub fn set_cost(&mut self, x: f32, z: f32, multiplier: f32) {
+        let (grid_x, grid_z) = self.world_to_grid(x, z);
+        if self.is_valid(grid_x, grid_z) {
+            let idx = self.get_index(grid_x, grid_z);
+            self.cost[idx] = multiplier;
+        }
+    }
+
+    /// Set the terrain height for a cell, used by jump/drop edge generation
+    pub fn set_height(&mut self, x: f32, z: f32, h: f32) {
+        let (grid_x, grid_z) = self.world_to_grid(x, z);
+        if self.is_valid(grid_x, grid_z) {
+            let idx = self.get_index(grid_x, grid_z);
+            self.heights[idx] = h;
+        }
+    }
+
     /// Check if a cell is walkable
     pub fn is_walkable(&self, x: f32, z: f32) -> bool {
         let (grid_x, grid_z) = self.world_to_grid(x, z);
@@ -307,11 +476,41 @@ impl Pathfinder {
         (dx * dx + dz * dz).sqrt()
     }
 
+    /// Wildfire-games-style goal fixup: if a cell is blocked, search outward in an
+    /// expanding ring for the nearest walkable cell instead of giving up, so clicking
+    /// on a rock or tree still moves the player adjacent to it.
+    fn nearest_walkable(&self, grid_x: i32, grid_z: i32) -> Option<(i32, i32)> {
+        let max_radius = self.grid_size as i32;
+
+        for radius in 0..=max_radius {
+            for dz in -radius..=radius {
+                for dx in -radius..=radius {
+                    // Only visit the ring at this radius, not the filled square
+                    if dx.abs() != radius && dz.abs() != radius {
+                        continue;
+                    }
+
+                    let cx = grid_x + dx;
+                    let cz = grid_z + dz;
+                    if !self.is_valid(cx, cz) {
+                        continue;
+                    }
+                    let idx = self.get_index(cx, cz);
+                    if self.grid[idx] {
+                        return Some((cx, cz));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
     /// Find path using A* algorithm
     /// Returns a flat array of [x1, z1, x2, z2, ...] coordinates in world space
     pub fn find_path(&self, start_x: f32, start_z: f32, goal_x: f32, goal_z: f32) -> Vec<f32> {
         let (start_grid_x, start_grid_z) = self.world_to_grid(start_x, start_z);
-        let (goal_grid_x, goal_grid_z) = self.world_to_grid(goal_x, goal_z);
+        let (mut goal_grid_x, mut goal_grid_z) = self.world_to_grid(goal_x, goal_z);
 
         // Check if start and goal are valid
         if !self.is_valid(start_grid_x, start_grid_z) ||
@@ -319,10 +518,17 @@ impl Pathfinder {
             return Vec::new();
         }
 
-        // Check if goal is walkable
+        // If the requested goal is blocked, fall back to the nearest walkable cell
+        // instead of failing outright (e.g. clicking on a rock or tree).
         let goal_idx = self.get_index(goal_grid_x, goal_grid_z);
         if !self.grid[goal_idx] {
-            return Vec::new();
+            match self.nearest_walkable(goal_grid_x, goal_grid_z) {
+                Some((x, z)) => {
+                    goal_grid_x = x;
+                    goal_grid_z = z;
+                }
+                None => return Vec::new(),
+            }
         }
 
         let mut open_set = BinaryHeap::new();
@@ -418,9 +624,523 @@ impl Pathfinder {
         Vec::new()
     }
 
+    /// Lowest per-cell cost multiplier anywhere on the grid, ignoring infinite costs and
+    /// capped at `WALK_COST` (so it never exceeds 1.0), used to keep the A* heuristic admissible when cells can cost less than 1.0.
+    fn min_cell_cost(&self) -> f32 {
+        self.cost.iter().copied().filter(|c| c.is_finite()).fold(WALK_COST, f32::min)
+    }
+
+    /// Find path using A*, weighted by the per-cell `cost` grid
+    /// Returns a flat array of [x1, z1, x2, z2, ...] coordinates in world space
+    pub fn find_path_weighted(&self, start_x: f32, start_z: f32, goal_x: f32, goal_z: f32) -> Vec<f32> {
+        let (start_grid_x, start_grid_z) = self.world_to_grid(start_x, start_z);
+        let (goal_grid_x, goal_grid_z) = self.world_to_grid(goal_x, goal_z);
+
+        if !self.is_valid(start_grid_x, start_grid_z) ||
+           !self.is_valid(goal_grid_x, goal_grid_z) {
+            return Vec::new();
+        }
+
+        let goal_idx = self.get_index(goal_grid_x, goal_grid_z);
+        if !self.grid[goal_idx] || self.cost[goal_idx].is_infinite() {
+            return Vec::new();
+        }
+
+        // Scale the heuristic by the cheapest cell on the grid so it never overestimates
+        // the true remaining cost (e.g. when roads cost less than 1.0) and A* stays optimal.
+        let heuristic_scale = self.min_cell_cost();
+
+        let mut open_set = BinaryHeap::new();
+        let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+        let mut g_scores: HashMap<(i32, i32), f32> = HashMap::new();
+
+        let start_node = PathNode {
+            x: start_grid_x,
+            z: start_grid_z,
+            g_cost: 0.0,
+            h_cost: self.heuristic(start_grid_x, start_grid_z, goal_grid_x, goal_grid_z) * heuristic_scale,
+            f_cost: 0.0,
+        };
+
+        g_scores.insert((start_grid_x, start_grid_z), 0.0);
+        open_set.push(start_node);
+
+        let directions = [
+            (0, 1), (1, 1), (1, 0), (1, -1),
+            (0, -1), (-1, -1), (-1, 0), (-1, 1)
+        ];
+
+        while let Some(current) = open_set.pop() {
+            if current.x == goal_grid_x && current.z == goal_grid_z {
+                let mut path = Vec::new();
+                let mut current_pos = (current.x, current.z);
+
+                let mut reverse_path = Vec::new();
+                reverse_path.push(current_pos);
+
+                while let Some(&prev_pos) = came_from.get(&current_pos) {
+                    reverse_path.push(prev_pos);
+                    current_pos = prev_pos;
+                }
+
+                for i in (0..reverse_path.len() - 1).rev() {
+                    let (grid_x, grid_z) = reverse_path[i];
+                    let (world_x, world_z) = self.grid_to_world(grid_x, grid_z);
+                    path.push(world_x);
+                    path.push(world_z);
+                }
+
+                return path;
+            }
+
+            for (dx, dz) in &directions {
+                let neighbor_x = current.x + dx;
+                let neighbor_z = current.z + dz;
+
+                if !self.is_valid(neighbor_x, neighbor_z) {
+                    continue;
+                }
+
+                let neighbor_idx = self.get_index(neighbor_x, neighbor_z);
+                if !self.grid[neighbor_idx] {
+                    continue; // Cell is blocked
+                }
+
+                let cell_cost = self.cost[neighbor_idx];
+                if cell_cost.is_infinite() {
+                    continue; // Cell is blocked via the cost grid
+                }
+
+                // Diagonal moves cost more, then scale by the destination cell's cost
+                let move_cost = if *dx != 0 && *dz != 0 { WALK_COST * DIAGONAL_MULTIPLIER } else { WALK_COST };
+                let tentative_g = current.g_cost + move_cost * cell_cost;
+
+                let neighbor_key = (neighbor_x, neighbor_z);
+                let current_g = g_scores.get(&neighbor_key).copied().unwrap_or(f32::INFINITY);
+
+                if tentative_g < current_g {
+                    came_from.insert(neighbor_key, (current.x, current.z));
+                    g_scores.insert(neighbor_key, tentative_g);
+
+                    let h_cost = self.heuristic(neighbor_x, neighbor_z, goal_grid_x, goal_grid_z) * heuristic_scale;
+                    let f_cost = tentative_g + h_cost;
+
+                    let neighbor_node = PathNode {
+                        x: neighbor_x,
+                        z: neighbor_z,
+                        g_cost: tentative_g,
+                        h_cost,
+                        f_cost,
+                    };
+
+                    open_set.push(neighbor_node);
+                }
+            }
+        }
+
+        // No path found
+        Vec::new()
+    }
+
+    /// Simulate a ballistic jump arc from (from_x, from_z) toward the cell `dist` steps
+    /// away in direction (dx, dz), rejecting it if the arc or landing would clip terrain
+    /// or the impact would be too hard to survive.
+    fn simulate_jump_arc(
+        &self,
+        from_x: i32, from_z: i32,
+        dx: i32, dz: i32, dist: i32,
+        speed: f32, jump_force: f32, gravity: f32,
+        max_landing_velocity: f32,
+    ) -> bool {
+        let target_x = from_x + dx * dist;
+        let target_z = from_z + dz * dist;
+        if !self.is_valid(target_x, target_z) {
+            return false;
+        }
+        let target_idx = self.get_index(target_x, target_z);
+        if !self.grid[target_idx] {
+            return false;
+        }
+        if speed <= 0.0 {
+            return false;
+        }
+
+        let start_height = self.heights[self.get_index(from_x, from_z)];
+        let total_distance = dist as f32 * self.cell_size;
+        // Integrate frame by frame, the same way `PlayerPhysics::update` advances: one
+        // `speed` worth of horizontal travel and one application of gravity per frame.
+        let steps = (total_distance / speed).ceil().max(1.0) as i32;
+
+        let mut traveled = 0.0;
+        let mut y = start_height;
+        let mut v_y = jump_force;
+
+        for step in 1..=steps {
+            y += v_y;
+            v_y += gravity;
+            traveled = (traveled + speed).min(total_distance);
+
+            let frac = traveled / total_distance;
+            let cell_x = from_x + (dx as f32 * dist as f32 * frac).round() as i32;
+            let cell_z = from_z + (dz as f32 * dist as f32 * frac).round() as i32;
+            if !self.is_valid(cell_x, cell_z) {
+                return false;
+            }
+            let idx = self.get_index(cell_x, cell_z);
+            if !self.grid[idx] {
+                return false;
+            }
+            // The arc clips the terrain below it before reaching the landing cell
+            if step < steps && y < self.heights[idx] {
+                return false;
+            }
+        }
+
+        let landing_height = self.heights[target_idx];
+        v_y < 0.0
+            && v_y.abs() <= max_landing_velocity
+            && (y - landing_height).abs() <= self.cell_size
+    }
+
+    /// Simulate stepping off a ledge onto a lower cell, returning the cost of the
+    /// drop if the landing is survivable, or `None` if it would be too hard a fall.
+    fn simulate_drop(&self, from_idx: usize, to_idx: usize, gravity: f32, max_landing_velocity: f32) -> Option<f32> {
+        let fall_height = self.heights[from_idx] - self.heights[to_idx];
+        if fall_height <= STEP_HEIGHT {
+            return None;
+        }
+
+        // v^2 = 2 * g * h, solved for the impact speed of a fall starting at rest
+        let impact_velocity = (2.0 * gravity.abs() * fall_height).sqrt();
+        if impact_velocity > max_landing_velocity {
+            return None;
+        }
+
+        let fall_cells = (fall_height / self.cell_size).round() as usize;
+        let scaled_fall_cost = self.fall_cost.get(fall_cells.min(MAX_FALL_LOOKUP - 1)).copied().unwrap_or(WALK_COST);
+        Some(DROP_COST + scaled_fall_cost)
+    }
+
+    /// Find a path that can include jump and drop edges, consuming the same
+    /// move_speed/jump_force/gravity parameters `PlayerPhysics` simulates with.
+    /// Returns a flat array of [x1, y1, z1, x2, y2, z2, ...] triples in world space.
+    pub fn find_path_3d(
+        &self,
+        start_x: f32, start_z: f32,
+        goal_x: f32, goal_z: f32,
+        move_speed: f32,
+        sprint_multiplier: f32,
+        is_sprinting: bool,
+        jump_force: f32,
+        gravity: f32,
+        max_landing_velocity: f32,
+    ) -> Vec<f32> {
+        let (start_grid_x, start_grid_z) = self.world_to_grid(start_x, start_z);
+        let (goal_grid_x, goal_grid_z) = self.world_to_grid(goal_x, goal_z);
+
+        if !self.is_valid(start_grid_x, start_grid_z) || !self.is_valid(goal_grid_x, goal_grid_z) {
+            return Vec::new();
+        }
+
+        let goal_idx = self.get_index(goal_grid_x, goal_grid_z);
+        if !self.grid[goal_idx] {
+            return Vec::new();
+        }
+
+        let speed = if is_sprinting { move_speed * sprint_multiplier } else { move_speed };
+
+        // Cheapest cost any edge could charge per cell of heuristic distance (a flat,
+        // non-diagonal step at the current base cost over the cheapest cell on the
+        // grid), so the heuristic never overestimates and A* stays admissible here
+        // the same way it does in `find_path_weighted`.
+        let base_cost = if is_sprinting { SPRINT_COST } else { WALK_COST };
+        let heuristic_scale = base_cost * self.min_cell_cost();
+
+        let mut open_set = BinaryHeap::new();
+        let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+        let mut g_scores: HashMap<(i32, i32), f32> = HashMap::new();
+
+        let start_node = PathNode {
+            x: start_grid_x,
+            z: start_grid_z,
+            g_cost: 0.0,
+            h_cost: self.heuristic(start_grid_x, start_grid_z, goal_grid_x, goal_grid_z) * heuristic_scale,
+            f_cost: 0.0,
+        };
+
+        g_scores.insert((start_grid_x, start_grid_z), 0.0);
+        open_set.push(start_node);
+
+        let directions = [
+            (0, 1), (1, 1), (1, 0), (1, -1),
+            (0, -1), (-1, -1), (-1, 0), (-1, 1)
+        ];
+
+        while let Some(current) = open_set.pop() {
+            if current.x == goal_grid_x && current.z == goal_grid_z {
+                let mut path = Vec::new();
+                let mut current_pos = (current.x, current.z);
+
+                let mut reverse_path = Vec::new();
+                reverse_path.push(current_pos);
+
+                while let Some(&prev_pos) = came_from.get(&current_pos) {
+                    reverse_path.push(prev_pos);
+                    current_pos = prev_pos;
+                }
+
+                for i in (0..reverse_path.len() - 1).rev() {
+                    let (grid_x, grid_z) = reverse_path[i];
+                    let (world_x, world_z) = self.grid_to_world(grid_x, grid_z);
+                    let height = self.heights[self.get_index(grid_x, grid_z)];
+                    path.push(world_x);
+                    path.push(height);
+                    path.push(world_z);
+                }
+
+                return path;
+            }
+
+            let current_idx = self.get_index(current.x, current.z);
+
+            // Flat walk / drop edges to the 8 immediate neighbors
+            for (dx, dz) in &directions {
+                let neighbor_x = current.x + dx;
+                let neighbor_z = current.z + dz;
+
+                if !self.is_valid(neighbor_x, neighbor_z) {
+                    continue;
+                }
+
+                let neighbor_idx = self.get_index(neighbor_x, neighbor_z);
+                if !self.grid[neighbor_idx] {
+                    continue;
+                }
+
+                let height_diff = self.heights[neighbor_idx] - self.heights[current_idx];
+                let base_cost = if is_sprinting { SPRINT_COST } else { WALK_COST };
+                let move_cost = if *dx != 0 && *dz != 0 { base_cost * DIAGONAL_MULTIPLIER } else { base_cost };
+
+                let edge_cost = if height_diff.abs() <= STEP_HEIGHT {
+                    Some(move_cost * self.cost[neighbor_idx])
+                } else if height_diff < 0.0 {
+                    self.simulate_drop(current_idx, neighbor_idx, gravity, max_landing_velocity)
+                } else {
+                    None // Too tall a step to walk and not a drop
+                };
+
+                let Some(edge_cost) = edge_cost else { continue };
+                self.relax(&mut came_from, &mut g_scores, &mut open_set, current.g_cost, edge_cost,
+                    (current.x, current.z), (neighbor_x, neighbor_z), goal_grid_x, goal_grid_z, heuristic_scale);
+            }
+
+            // Jump edges further out in each of the 8 directions
+            for (dx, dz) in &directions {
+                for dist in 2..=MAX_JUMP_CELLS {
+                    let target_x = current.x + dx * dist;
+                    let target_z = current.z + dz * dist;
+                    if !self.is_valid(target_x, target_z) {
+                        break;
+                    }
+                    if !self.simulate_jump_arc(current.x, current.z, *dx, *dz, dist, speed, jump_force, gravity, max_landing_velocity) {
+                        continue;
+                    }
+
+                    let target_idx = self.get_index(target_x, target_z);
+                    let edge_cost = JUMP_COST * dist as f32 * self.cost[target_idx];
+                    self.relax(&mut came_from, &mut g_scores, &mut open_set, current.g_cost, edge_cost,
+                        (current.x, current.z), (target_x, target_z), goal_grid_x, goal_grid_z, heuristic_scale);
+                }
+            }
+        }
+
+        // No path found
+        Vec::new()
+    }
+
+    /// Shared A* relaxation step: update g/f scores and push the neighbor if this edge improves it
+    fn relax(
+        &self,
+        came_from: &mut HashMap<(i32, i32), (i32, i32)>,
+        g_scores: &mut HashMap<(i32, i32), f32>,
+        open_set: &mut BinaryHeap<PathNode>,
+        current_g: f32,
+        edge_cost: f32,
+        from: (i32, i32),
+        to: (i32, i32),
+        goal_x: i32,
+        goal_z: i32,
+        heuristic_scale: f32,
+    ) {
+        let tentative_g = current_g + edge_cost;
+        let current_g_score = g_scores.get(&to).copied().unwrap_or(f32::INFINITY);
+
+        if tentative_g < current_g_score {
+            came_from.insert(to, from);
+            g_scores.insert(to, tentative_g);
+
+            let h_cost = self.heuristic(to.0, to.1, goal_x, goal_z) * heuristic_scale;
+            open_set.push(PathNode {
+                x: to.0,
+                z: to.1,
+                g_cost: tentative_g,
+                h_cost,
+                f_cost: tentative_g + h_cost,
+            });
+        }
+    }
+
+    /// Check whether a straight line between two world positions stays entirely over
+    /// walkable cells. Uses a Bresenham traversal over the max(dx,dz) + 1 cells between
+    /// the two points and, on a diagonal step, rejects the line if both orthogonal
+    /// cells at that corner are blocked, so it can't cut through the gap between two
+    /// diagonally-adjacent obstacles. A diagonal step past a single blocked corner
+    /// cell is allowed, so not every cell the line touches is checked. Public so
+    /// callers can use it as a cheap straight-line movement check before running a
+    /// full A* query.
+    pub fn cell_line_clear(&self, x1: f32, z1: f32, x2: f32, z2: f32) -> bool {
+        let (mut cell_x, mut cell_z) = self.world_to_grid(x1, z1);
+        let (goal_x, goal_z) = self.world_to_grid(x2, z2);
+
+        if !self.is_valid(cell_x, cell_z) || !self.is_valid(goal_x, goal_z) {
+            return false;
+        }
+
+        let start_idx = self.get_index(cell_x, cell_z);
+        if !self.grid[start_idx] {
+            return false;
+        }
+
+        let step_x = (goal_x - cell_x).signum();
+        let step_z = (goal_z - cell_z).signum();
+        let dx = (goal_x - cell_x).abs();
+        let dz = (goal_z - cell_z).abs();
+        let mut err = dx - dz;
+
+        while cell_x != goal_x || cell_z != goal_z {
+            let e2 = 2 * err;
+            let move_x = e2 > -dz;
+            let move_z = e2 < dx;
+
+            if move_x {
+                err -= dz;
+            }
+            if move_z {
+                err += dx;
+            }
+
+            if move_x && move_z {
+                // Diagonal step: reject it if both orthogonal neighbors are blocked,
+                // which would mean the line is cutting through a blocked corner.
+                let side_x_blocked = !self.is_valid(cell_x + step_x, cell_z)
+                    || !self.grid[self.get_index(cell_x + step_x, cell_z)];
+                let side_z_blocked = !self.is_valid(cell_x, cell_z + step_z)
+                    || !self.grid[self.get_index(cell_x, cell_z + step_z)];
+                if side_x_blocked && side_z_blocked {
+                    return false;
+                }
+            }
+
+            if move_x {
+                cell_x += step_x;
+            }
+            if move_z {
+                cell_z += step_z;
+            }
+
+            if !self.is_valid(cell_x, cell_z) {
+                return false;
+            }
+            let idx = self.get_index(cell_x, cell_z);
+            if !self.grid[idx] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Smooth a flat [x1, z1, x2, z2, ...] path returned by `find_path` by string-pulling:
+    /// keep an anchor waypoint and skip ahead to the farthest point still in line of sight,
+    /// removing the 8-direction zig-zag the raw grid search produces.
+    pub fn smooth_path(&self, path: Vec<f32>) -> Vec<f32> {
+        // Ignore a trailing unpaired coordinate rather than indexing into it
+        let waypoints: Vec<(f32, f32)> = path.chunks_exact(2).map(|c| (c[0], c[1])).collect();
+
+        if waypoints.len() <= 2 {
+            return waypoints.into_iter().flat_map(|(x, z)| [x, z]).collect();
+        }
+
+        let mut smoothed = Vec::new();
+        let mut anchor = waypoints[0];
+        smoothed.push(anchor.0);
+        smoothed.push(anchor.1);
+
+        let mut i = 1;
+        while i < waypoints.len() {
+            let mut farthest = i;
+
+            for j in (i + 1)..waypoints.len() {
+                if self.cell_line_clear(anchor.0, anchor.1, waypoints[j].0, waypoints[j].1) {
+                    farthest = j;
+                } else {
+                    break;
+                }
+            }
+
+            anchor = waypoints[farthest];
+            smoothed.push(anchor.0);
+            smoothed.push(anchor.1);
+            i = farthest + 1;
+        }
+
+        smoothed
+    }
+
+    /// Check whether a footprint of the given radius, centered on (x, z), is fully
+    /// supported: ported from the idea behind Quake's `SV_CheckBottom`, this samples
+    /// the four corners and the center of the footprint and returns false if any
+    /// corner is blocked, out of bounds, or sits on a significantly different height
+    /// than the center (i.e. the footprint overhangs an edge or teeters on a lip).
+    pub fn check_bottom(&self, x: f32, z: f32, radius: f32) -> bool {
+        let (center_grid_x, center_grid_z) = self.world_to_grid(x, z);
+        if !self.is_valid(center_grid_x, center_grid_z) {
+            return false;
+        }
+        let center_idx = self.get_index(center_grid_x, center_grid_z);
+        if !self.grid[center_idx] {
+            return false;
+        }
+        let center_height = self.heights[center_idx];
+
+        let corners = [
+            (x - radius, z - radius),
+            (x + radius, z - radius),
+            (x - radius, z + radius),
+            (x + radius, z + radius),
+        ];
+
+        for (corner_x, corner_z) in corners {
+            let (grid_x, grid_z) = self.world_to_grid(corner_x, corner_z);
+            if !self.is_valid(grid_x, grid_z) {
+                return false; // Footprint overhangs the edge of the grid
+            }
+            let idx = self.get_index(grid_x, grid_z);
+            if !self.grid[idx] {
+                return false; // Footprint overhangs a blocked cell
+            }
+            if (center_height - self.heights[idx]).abs() > STEP_HEIGHT {
+                return false; // Footprint overhangs a ledge
+            }
+        }
+
+        true
+    }
+
     /// Get a random walkable position within bounds
     pub fn get_random_walkable_position(&self, center_x: f32, center_z: f32, radius: f32) -> Vec<f32> {
         let max_attempts = 50;
+        let footprint_radius = self.cell_size * 0.4;
 
         for _ in 0..max_attempts {
             // Generate random angle and distance
@@ -430,7 +1150,7 @@ impl Pathfinder {
             let x = center_x + angle.cos() * distance;
             let z = center_z + angle.sin() * distance;
 
-            if self.is_walkable(x, z) {
+            if self.check_bottom(x, z, footprint_radius) {
                 return vec![x, z];
             }
         }